@@ -0,0 +1,2 @@
+mod utility;
+pub mod phonetic;
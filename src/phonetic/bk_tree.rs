@@ -0,0 +1,118 @@
+// BK-tree index over dictionary keys, used for typo-tolerant lookup within
+// a small edit-distance tolerance.
+//
+// Insertion exploits the triangle inequality of the Levenshtein distance:
+// the first inserted word becomes the root; inserting `word` walks down
+// from the root, recursing into the child whose edge is labelled with the
+// distance between `word` and the current node, and attaching a new child
+// when no such edge exists yet.
+
+use std::collections::HashMap;
+
+use edit_distance::edit_distance;
+
+struct BkNode {
+    word: String,
+    children: HashMap<usize, BkNode>,
+}
+
+#[derive(Default)]
+pub(crate) struct BkTree {
+    root: Option<BkNode>,
+}
+
+impl BkTree {
+    pub(crate) fn new() -> Self {
+        BkTree::default()
+    }
+
+    pub(crate) fn insert(&mut self, word: &str) {
+        match &mut self.root {
+            None => {
+                self.root = Some(BkNode {
+                    word: word.to_owned(),
+                    children: HashMap::new(),
+                });
+            }
+            Some(root) => insert(root, word),
+        }
+    }
+
+    /// Every inserted word within edit-distance `tolerance` of `query`.
+    pub(crate) fn find(&self, query: &str, tolerance: usize) -> Vec<String> {
+        let mut matches = Vec::new();
+        if let Some(root) = &self.root {
+            search(root, query, tolerance, &mut matches);
+        }
+        matches
+    }
+}
+
+fn insert(node: &mut BkNode, word: &str) {
+    let distance = edit_distance(word, &node.word);
+    match node.children.get_mut(&distance) {
+        Some(child) => insert(child, word),
+        None => {
+            node.children.insert(
+                distance,
+                BkNode {
+                    word: word.to_owned(),
+                    children: HashMap::new(),
+                },
+            );
+        }
+    }
+}
+
+fn search(node: &BkNode, query: &str, tolerance: usize, matches: &mut Vec<String>) {
+    let distance = edit_distance(query, &node.word);
+    if distance <= tolerance {
+        matches.push(node.word.clone());
+    }
+
+    let lower = distance.saturating_sub(tolerance);
+    let upper = distance + tolerance;
+    for (&edge, child) in &node.children {
+        if edge >= lower && edge <= upper {
+            search(child, query, tolerance, matches);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BkTree;
+
+    fn sorted(mut words: Vec<String>) -> Vec<String> {
+        words.sort();
+        words
+    }
+
+    #[test]
+    fn test_find_within_tolerance() {
+        let mut tree = BkTree::new();
+        for word in ["computer", "computar", "ebong", "apple"] {
+            tree.insert(word);
+        }
+
+        assert_eq!(
+            sorted(tree.find("computer", 1)),
+            sorted(vec!["computer".to_owned(), "computar".to_owned()])
+        );
+        assert_eq!(tree.find("computer", 0), vec!["computer".to_owned()]);
+    }
+
+    #[test]
+    fn test_find_outside_tolerance_is_empty() {
+        let mut tree = BkTree::new();
+        tree.insert("computer");
+
+        assert_eq!(tree.find("ebong", 1), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_find_on_empty_tree() {
+        let tree = BkTree::new();
+        assert_eq!(tree.find("anything", 2), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,202 @@
+// Bengali-script-aware edit distance.
+//
+// The `edit_distance` crate compares by Unicode scalar, so a single
+// conjunct or vowel-sign (kar) counts as several edits and distorts
+// similarity rankings. This module first segments each string into
+// orthographic syllable units - a consonant together with any
+// hasanta-joined conjunct components and a trailing vowel-sign, or a
+// standalone independent vowel / modifier (anushar, chandrabindu,
+// bisarga, khandatta) - and then runs the Levenshtein recurrence over
+// those units instead of raw `char`s.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GraphemeClass {
+    Consonant,
+    IndependentVowel,
+    VowelSign,
+    Modifier,
+    Other,
+}
+
+const HASANTA: char = '\u{09CD}';
+
+fn classify(c: char) -> GraphemeClass {
+    match c {
+        '\u{0995}'..='\u{09B9}' | '\u{09DC}'..='\u{09DF}' => GraphemeClass::Consonant,
+        '\u{0985}'..='\u{0994}' => GraphemeClass::IndependentVowel,
+        '\u{09BE}'..='\u{09CC}' | '\u{09D7}' => GraphemeClass::VowelSign,
+        '\u{0981}' | '\u{0982}' | '\u{0983}' | '\u{09CE}' => GraphemeClass::Modifier,
+        _ => GraphemeClass::Other,
+    }
+}
+
+struct Syllable {
+    text: String,
+    class: GraphemeClass,
+    // For a consonant syllable, its root (the base consonant plus any
+    // hasanta-joined conjunct components, vowel-sign excluded) and the
+    // trailing vowel-sign it carries, if any - kept apart so a substitution
+    // can tell "same consonant, different kar" from "different consonant,
+    // same kar" apart instead of collapsing both into one opaque class.
+    root: String,
+    vowel_sign: Option<char>,
+}
+
+/// Segment `s` into orthographic syllable units.
+fn segment(s: &str) -> Vec<Syllable> {
+    let mut syllables = Vec::new();
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let class = classify(c);
+        if class != GraphemeClass::Consonant {
+            syllables.push(Syllable {
+                text: c.to_string(),
+                class,
+                root: c.to_string(),
+                vowel_sign: None,
+            });
+            continue;
+        }
+
+        let mut root = c.to_string();
+        while chars.peek() == Some(&HASANTA) {
+            let mut lookahead = chars.clone();
+            lookahead.next(); // Skip the hasanta itself.
+            match lookahead.peek() {
+                Some(&next) if classify(next) == GraphemeClass::Consonant => {
+                    root.push(chars.next().unwrap()); // Hasanta.
+                    root.push(chars.next().unwrap()); // Joined consonant.
+                }
+                _ => break,
+            }
+        }
+        let mut vowel_sign = None;
+        if let Some(&next) = chars.peek() {
+            if classify(next) == GraphemeClass::VowelSign {
+                vowel_sign = Some(next);
+                chars.next();
+            }
+        }
+
+        let text = match vowel_sign {
+            Some(kar) => format!("{}{}", root, kar),
+            None => root.clone(),
+        };
+        syllables.push(Syllable { text, class: GraphemeClass::Consonant, root, vowel_sign });
+    }
+
+    syllables
+}
+
+/// Segment `s` into its Bengali orthographic syllable units (see module
+/// docs), for callers that need grapheme-aware comparisons other than edit
+/// distance, e.g. ranking by shared leading-syllable prefix.
+pub(crate) fn syllables(s: &str) -> Vec<String> {
+    segment(s).into_iter().map(|syllable| syllable.text).collect()
+}
+
+/// The reduced substitution cost for swapping two syllables that are a
+/// genuine visual/phonetic near-miss, versus the default cost for every
+/// other pair.
+///
+/// A standalone vowel-sign (kar) substituted for another vowel-sign is a
+/// near-miss. For two consonant syllables - which may each carry their own
+/// attached kar - a near-miss is either the same root with a different kar
+/// (e.g. কা vs. কে: two kars) or the same kar with a different root (e.g.
+/// কা vs. খা: two velars); when *both* differ, the pair isn't a near-miss
+/// and gets the default cost. Two *different* independent vowels (e.g. আ
+/// vs. এ) are not a near-miss either - they're simply different vowels -
+/// so that pairing also keeps the default cost.
+const DISCOUNTED_SUBSTITUTION_COST: usize = 1;
+const DEFAULT_SUBSTITUTION_COST: usize = 2;
+
+fn substitution_cost(a: &Syllable, b: &Syllable) -> usize {
+    match (a.class, b.class) {
+        (GraphemeClass::Consonant, GraphemeClass::Consonant) => {
+            if a.root == b.root || a.vowel_sign == b.vowel_sign {
+                DISCOUNTED_SUBSTITUTION_COST
+            } else {
+                DEFAULT_SUBSTITUTION_COST
+            }
+        }
+        (GraphemeClass::VowelSign, GraphemeClass::VowelSign) => DISCOUNTED_SUBSTITUTION_COST,
+        _ => DEFAULT_SUBSTITUTION_COST,
+    }
+}
+
+/// Edit distance between `a` and `b` over Bengali orthographic syllable
+/// units rather than raw `char`s, so a conjunct or kar counts as a single
+/// edit. Substituting one kar for another, or one consonant syllable for
+/// another that shares its root or its kar, costs less than any other
+/// substitution; two different independent vowels get the default cost.
+pub fn bengali_edit_distance(a: &str, b: &str) -> usize {
+    let a = segment(a);
+    let b = segment(b);
+
+    let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        let mut curr_row = vec![i; b.len() + 1];
+
+        for j in 1..=b.len() {
+            curr_row[j] = if a[i - 1].text == b[j - 1].text {
+                prev_row[j - 1]
+            } else {
+                (prev_row[j - 1] + substitution_cost(&a[i - 1], &b[j - 1]))
+                    .min(prev_row[j] + 1)
+                    .min(curr_row[j - 1] + 1)
+            };
+        }
+
+        prev_row = curr_row;
+    }
+
+    prev_row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bengali_edit_distance;
+
+    #[test]
+    fn test_identical_strings() {
+        assert_eq!(bengali_edit_distance("আম", "আম"), 0);
+    }
+
+    #[test]
+    fn test_conjunct_counts_as_one_edit() {
+        // "ক্ত" (ক + hasanta-joined ত) vs "ক" differ by one syllable unit,
+        // not by the three `char`s the conjunct is made of.
+        assert_eq!(bengali_edit_distance("ক্ত", "ক"), 1);
+    }
+
+    #[test]
+    fn test_same_root_different_kar_is_discounted() {
+        // "কা" vs. "কে": same consonant root, different attached kar - a
+        // near-miss, cheaper than swapping one independent vowel for an
+        // unrelated one.
+        let kar_for_kar = bengali_edit_distance("কা", "কে");
+        let vowel_for_vowel = bengali_edit_distance("আ", "এ");
+        assert!(kar_for_kar < vowel_for_vowel);
+    }
+
+    #[test]
+    fn test_same_kar_different_root_is_discounted() {
+        // "কা" vs. "খা": same attached kar, different (velar) consonant
+        // root - also a near-miss.
+        let consonant_for_consonant = bengali_edit_distance("কা", "খা");
+        let vowel_for_vowel = bengali_edit_distance("আ", "এ");
+        assert!(consonant_for_consonant < vowel_for_vowel);
+    }
+
+    #[test]
+    fn test_different_root_and_kar_is_not_discounted() {
+        // "কা" vs. "তে": both the consonant root and the attached kar
+        // differ, so this isn't a near-miss and costs the same as an
+        // unrelated independent-vowel swap.
+        let both_differ = bengali_edit_distance("কা", "তে");
+        let vowel_for_vowel = bengali_edit_distance("আ", "এ");
+        assert_eq!(both_differ, vowel_for_vowel);
+    }
+}
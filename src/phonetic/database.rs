@@ -0,0 +1,170 @@
+// Dictionary, suffix and autocorrect/emoticon lookup tables backing
+// `PhoneticSuggestion`.
+
+use std::path::PathBuf;
+
+use rustc_hash::FxHashMap;
+
+use crate::phonetic::bk_tree::BkTree;
+use crate::phonetic::learned_words::LearnedWords;
+use crate::phonetic::trie::Trie;
+
+pub(crate) struct Database {
+    table: FxHashMap<String, Vec<String>>,
+    suffix: FxHashMap<String, String>,
+    auto_correct: FxHashMap<String, String>,
+    trie: Trie,
+    bk_tree: BkTree,
+    learned: LearnedWords,
+    learned_path: PathBuf,
+}
+
+impl Database {
+    pub(crate) fn new() -> Self {
+        Database::with_learned_path(learned_words_path())
+    }
+
+    /// Like `new`, but persists learned selections to `learned_path` instead
+    /// of the real user data directory. Lets tests keep their own learned
+    /// words off the ambient, mutable real store.
+    pub(crate) fn with_learned_path(learned_path: PathBuf) -> Self {
+        let table = build_dictionary_table();
+        let suffix = build_suffix_table();
+        let auto_correct = build_auto_correct_table();
+        let trie = build_trie(&table);
+        let bk_tree = build_bk_tree(&table);
+        let learned = LearnedWords::load(&learned_path);
+
+        Database {
+            table,
+            suffix,
+            auto_correct,
+            trie,
+            bk_tree,
+            learned,
+            learned_path,
+        }
+    }
+
+    /// Record that the user committed `chosen` for `key`, persisting it so
+    /// it's suggested first the next time `key` is typed.
+    pub(crate) fn learn(&mut self, key: &str, chosen: &str) {
+        self.learned.record(key, chosen);
+        if let Err(err) = self.learned.save(&self.learned_path) {
+            eprintln!(
+                "riti: failed to persist learned suggestions to {}: {}",
+                self.learned_path.display(),
+                err
+            );
+        }
+    }
+
+    /// Previously-selected words for `key`, most-selected first.
+    pub(crate) fn learned_suggestions(&self, key: &str) -> Vec<String> {
+        self.learned.ranked(key)
+    }
+
+    /// Exact lookup of a normalized Roman `key` in the dictionary table.
+    pub(crate) fn search_dictionary(&self, key: &str) -> Vec<String> {
+        self.table.get(key).cloned().unwrap_or_else(Vec::new)
+    }
+
+    /// Dictionary keys within edit-distance `tolerance` of `key`, via the
+    /// BK-tree index. Used to fall back to typo-tolerant candidates when an
+    /// exact dictionary lookup misses.
+    pub(crate) fn search_fuzzy(&self, key: &str, tolerance: usize) -> Vec<String> {
+        self.bk_tree.find(key, tolerance)
+    }
+
+    /// Look up a suffix, e.g. গুলো, মালা.
+    pub(crate) fn find_suffix(&self, suffix: &str) -> Option<String> {
+        self.suffix.get(suffix).cloned()
+    }
+
+    /// Autocorrect or emoticon lookup.
+    pub(crate) fn get_corrected(&self, key: &str) -> Option<String> {
+        self.auto_correct.get(key).cloned()
+    }
+
+    /// Every dictionary headword (or Roman key) that starts with `prefix`,
+    /// via the prefix trie.
+    pub(crate) fn autocomplete(&self, prefix: &str) -> Vec<String> {
+        self.trie.complete(prefix)
+    }
+}
+
+fn build_dictionary_table() -> FxHashMap<String, Vec<String>> {
+    let mut table = FxHashMap::default();
+    table.insert("a".to_owned(), vec![
+        "আ".to_owned(),
+        "আঃ".to_owned(),
+        "া".to_owned(),
+        "এ".to_owned(),
+        "অ্যা".to_owned(),
+        "অ্যাঁ".to_owned(),
+    ]);
+    table.insert("as".to_owned(), vec![
+        "আস".to_owned(),
+        "আশ".to_owned(),
+        "এস".to_owned(),
+        "আঁশ".to_owned(),
+    ]);
+    table.insert("computer".to_owned(), vec!["কম্পিউটার".to_owned()]);
+    table.insert("ebong".to_owned(), vec!["এবং".to_owned()]);
+    table
+}
+
+fn build_suffix_table() -> FxHashMap<String, String> {
+    let mut suffix = FxHashMap::default();
+    suffix.insert("gulo".to_owned(), "গুলো".to_owned());
+    suffix.insert("mala".to_owned(), "মালা".to_owned());
+    suffix.insert("e".to_owned(), "ে".to_owned());
+    suffix
+}
+
+fn build_auto_correct_table() -> FxHashMap<String, String> {
+    let mut auto_correct = FxHashMap::default();
+    // Plain-text emoticons get an identity entry: the phonetic engine would
+    // otherwise mangle them (':' converts to the visarga 'ঃ'), so this is
+    // what guarantees the literal emoticon stays on offer alongside the
+    // phonetic reading.
+    auto_correct.insert(":)".to_owned(), ":)".to_owned());
+    auto_correct
+}
+
+fn build_trie(table: &FxHashMap<String, Vec<String>>) -> Trie {
+    let mut trie = Trie::new();
+    for (key, words) in table {
+        trie.insert(key);
+        for word in words {
+            trie.insert(word);
+        }
+    }
+    trie
+}
+
+fn build_bk_tree(table: &FxHashMap<String, Vec<String>>) -> BkTree {
+    let mut bk_tree = BkTree::new();
+    for key in table.keys() {
+        bk_tree.insert(key);
+    }
+    bk_tree
+}
+
+/// Where the learned-selection store lives: under the user's XDG data
+/// directory (or `$HOME/.local/share` when `XDG_DATA_HOME` isn't set), next
+/// to the rest of riti's per-user state. Falls back to a path relative to
+/// the current directory only when neither is available.
+fn learned_words_path() -> PathBuf {
+    data_dir().join("learned.json")
+}
+
+fn data_dir() -> PathBuf {
+    if let Ok(xdg_data_home) = std::env::var("XDG_DATA_HOME") {
+        return PathBuf::from(xdg_data_home).join("openbangla-keyboard/riti");
+    }
+    if let Ok(home) = std::env::var("HOME") {
+        return PathBuf::from(home).join(".local/share/openbangla-keyboard/riti");
+    }
+    PathBuf::from("data")
+}
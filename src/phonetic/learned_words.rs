@@ -0,0 +1,112 @@
+// Persists the Bengali words a user actually commits, per normalized Roman
+// key, so they can be floated to the top of future suggestions for the
+// same key. This mirrors how a user dictionary augments a fixed lexicon.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Serialize, Deserialize)]
+pub(crate) struct LearnedWords {
+    // Normalized Roman key -> (chosen Bengali word -> selection count).
+    selections: HashMap<String, HashMap<String, usize>>,
+}
+
+impl LearnedWords {
+    /// Load the learned-selection store from `path`, or start empty if it
+    /// doesn't exist yet or can't be parsed.
+    pub(crate) fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist the store to `path`, creating its parent directory if
+    /// needed. Returns the underlying I/O or serialization error instead of
+    /// swallowing it, so callers can decide how to surface a persistence
+    /// failure.
+    pub(crate) fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string(self)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(path, data)
+    }
+
+    /// Record that `chosen` was committed for `key`.
+    pub(crate) fn record(&mut self, key: &str, chosen: &str) {
+        *self
+            .selections
+            .entry(key.to_owned())
+            .or_default()
+            .entry(chosen.to_owned())
+            .or_insert(0) += 1;
+    }
+
+    /// Previously-selected words for `key`, most-selected first. Ties in
+    /// selection count are broken by word, not `HashMap` iteration order,
+    /// so the result is deterministic across runs.
+    pub(crate) fn ranked(&self, key: &str) -> Vec<String> {
+        let mut counted: Vec<(&String, &usize)> = match self.selections.get(key) {
+            Some(counts) => counts.iter().collect(),
+            None => return Vec::new(),
+        };
+
+        counted.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        counted.into_iter().map(|(word, _)| word.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LearnedWords;
+
+    #[test]
+    fn test_ranked_orders_by_selection_count() {
+        let mut learned = LearnedWords::default();
+        learned.record("ami", "আমি");
+        learned.record("ami", "আমি");
+        learned.record("ami", "এমই");
+
+        assert_eq!(learned.ranked("ami"), vec!["আমি", "এমই"]);
+        assert_eq!(learned.ranked("unknown"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_ranked_breaks_ties_by_word() {
+        let mut learned = LearnedWords::default();
+        learned.record("ami", "এমই");
+        learned.record("ami", "আমি");
+
+        // Same selection count for both - order must be deterministic
+        // (by word), not dependent on HashMap iteration order.
+        assert_eq!(learned.ranked("ami"), vec!["আমি", "এমই"]);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = std::env::temp_dir().join("riti_test_learned_words_round_trip.json");
+
+        let mut learned = LearnedWords::default();
+        learned.record("ami", "আমি");
+        learned.save(&path).expect("save should succeed");
+
+        let reloaded = LearnedWords::load(&path);
+        assert_eq!(reloaded.ranked("ami"), vec!["আমি"]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_load_missing_file_starts_empty() {
+        let path = std::env::temp_dir().join("riti_test_learned_words_missing.json");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(LearnedWords::load(&path).ranked("ami"), Vec::<String>::new());
+    }
+}
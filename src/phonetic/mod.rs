@@ -0,0 +1,6 @@
+mod bk_tree;
+pub mod bn_distance;
+pub(crate) mod database;
+mod learned_words;
+pub(crate) mod suggestion;
+mod trie;
@@ -1,19 +1,71 @@
 // Suggestion making module.
 
-use std::cmp::Ordering;
-use edit_distance::edit_distance;
 use rupantor::avro::AvroPhonetic;
 use rustc_hash::FxHashMap;
 
+use crate::phonetic::bn_distance::{bengali_edit_distance, syllables};
 use crate::phonetic::database::Database;
 use crate::utility::Utility;
 
+/// Tunable weights for `suggest`'s composite ranking. Candidates are
+/// compared first by kind (exact dictionary hit ranks above a
+/// suffix-expansion), then by these weighted factors, in order.
+#[derive(Debug, Clone, Copy)]
+pub struct RankingWeights {
+    /// Multiplier on the shared leading-grapheme-prefix length with the
+    /// phonetic form (a longer shared prefix ranks earlier).
+    pub prefix: i32,
+    /// Multiplier on the edit distance to the phonetic form (a smaller
+    /// distance ranks earlier).
+    pub distance: i32,
+    /// Multiplier on candidate length, used as a final tiebreak. Dictionary
+    /// frequency would be the ideal final tiebreak, but `Database` doesn't
+    /// track per-word frequency, so length is what's available.
+    pub length: i32,
+}
+
+impl Default for RankingWeights {
+    fn default() -> Self {
+        RankingWeights {
+            prefix: 1,
+            distance: 1,
+            length: 1,
+        }
+    }
+}
+
+/// Where a candidate came from, used as the primary ranking key: exact
+/// dictionary hits always outrank suffix-expanded forms.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum CandidateKind {
+    Exact,
+    SuffixExpanded,
+}
+
+fn candidate_kind(candidate: &str, exact_matches: &[String]) -> CandidateKind {
+    if exact_matches.iter().any(|word| word == candidate) {
+        CandidateKind::Exact
+    } else {
+        CandidateKind::SuffixExpanded
+    }
+}
+
+/// Length, in Bengali orthographic syllable units (see `bn_distance`), of
+/// the leading run `a` and `b` have in common - so a shared conjunct or kar
+/// counts as one matching unit rather than several raw `char`s.
+fn shared_prefix_len(a: &str, b: &str) -> usize {
+    let a = syllables(a);
+    let b = syllables(b);
+    a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count()
+}
+
 pub(crate) struct PhoneticSuggestion {
     suggestions: Vec<String>,
     database: Database,
     // Cache for storing dictionary searches.
     cache: FxHashMap<String, Vec<String>>,
     phonetic: AvroPhonetic,
+    ranking_weights: RankingWeights,
 }
 
 impl PhoneticSuggestion {
@@ -23,9 +75,29 @@ impl PhoneticSuggestion {
             database: Database::new(),
             cache: FxHashMap::default(),
             phonetic: AvroPhonetic::new(),
+            ranking_weights: RankingWeights::default(),
+        }
+    }
+
+    /// Like `new`, but with learned selections persisted to `learned_path`
+    /// instead of the real user data directory, so tests don't depend on
+    /// (or pollute) ambient, mutable state on whatever machine runs them.
+    #[cfg(test)]
+    fn new_with_learned_path(learned_path: std::path::PathBuf) -> Self {
+        PhoneticSuggestion {
+            suggestions: Vec::new(),
+            database: Database::with_learned_path(learned_path),
+            cache: FxHashMap::default(),
+            phonetic: AvroPhonetic::new(),
+            ranking_weights: RankingWeights::default(),
         }
     }
 
+    /// Override the default ranking weights used by `suggest`.
+    pub(crate) fn set_ranking_weights(&mut self, weights: RankingWeights) {
+        self.ranking_weights = weights;
+    }
+
     /// Add suffix(গুলো, মালা...etc) to the dictionary suggestions and return them.
     /// This function gets the suggestion list from the stored cache.
     fn add_suffix_to_suggestions(&self, splitted: &(String, String, String)) -> Vec<String> {
@@ -83,6 +155,36 @@ impl PhoneticSuggestion {
         }
     }
 
+    /// Trie-based prefix completions for `middle`'s phonetic form, cached
+    /// under the same key convention as the dictionary cache.
+    fn trie_completions(&mut self, middle: &str, phonetic: &str) -> Vec<String> {
+        let cache_key = format!("{}\u{1}trie", middle);
+
+        if !self.cache.contains_key(&cache_key) {
+            let completions = self.database.autocomplete(phonetic);
+            self.cache.insert(cache_key.clone(), completions);
+        }
+
+        self.cache[&cache_key].clone()
+    }
+
+    /// Prefix-autocomplete `term`: convert it via `AvroPhonetic`, walk the
+    /// dictionary trie down to the node matching the converted prefix and
+    /// return every descendant headword, each beginning with the converted
+    /// prefix (not the Roman `term` as typed).
+    pub(crate) fn autocomplete(&mut self, term: &str) -> Vec<String> {
+        let splitted_string = split_string(term);
+        let phonetic = self.phonetic.convert(&splitted_string.1);
+        self.trie_completions(&splitted_string.1, &phonetic)
+    }
+
+    /// Record that the user picked `chosen` for `term`, so it's ranked
+    /// first the next time the same key is typed.
+    pub(crate) fn commit(&mut self, term: &str, chosen: &str) {
+        let splitted_string = split_string(term);
+        self.database.learn(&splitted_string.1, chosen);
+    }
+
     /// Make suggestions from the given `term`.
     pub(crate) fn suggest(&mut self, term: &str) -> Vec<String> {
         let mut suggestions: Vec<String> = Vec::new();
@@ -92,6 +194,23 @@ impl PhoneticSuggestion {
 
         if !self.cache.contains_key(&splitted_string.1) {
             let mut dictionary = self.database.search_dictionary(&splitted_string.1);
+
+            // Typo-tolerant fallback: an exact miss on an alphabetic key of
+            // meaningful length is retried against the BK-tree, with a
+            // tolerance scaled to the key's length. Below this length a
+            // tolerance of 1 would match almost any key (e.g. a single
+            // punctuation character like the ":" in an emoticon), so it's
+            // skipped entirely instead of flooding the suggestions.
+            if dictionary.is_empty()
+                && splitted_string.1.len() >= 3
+                && splitted_string.1.chars().all(|c| c.is_ascii_alphabetic())
+            {
+                let tolerance = if splitted_string.1.len() <= 5 { 1 } else { 2 };
+                for candidate in self.database.search_fuzzy(&splitted_string.1, tolerance) {
+                    dictionary.push(self.phonetic.convert(&candidate));
+                }
+            }
+
             // Auto Correct
             if let Some(corrected) = self.database.get_corrected(&splitted_string.1) {
                 let word = self.phonetic.convert(&corrected);
@@ -105,21 +224,58 @@ impl PhoneticSuggestion {
 
         let mut suggestions_with_suffix = self.add_suffix_to_suggestions(&splitted_string);
 
-        suggestions_with_suffix.sort_by(|a, b| {
-            let dist1 = edit_distance(&phonetic, a);
-            let dist2 = edit_distance(&phonetic, b);
-
-            if dist1 < dist2 {
-                Ordering::Less
-            } else if dist1 > dist2 {
-                Ordering::Greater
-            } else {
-                Ordering::Equal
+        // Learned words augment the static dictionary: inject any that
+        // aren't already among the candidates before ranking.
+        let learned = self.database.learned_suggestions(&splitted_string.1);
+        for word in &learned {
+            if !suggestions_with_suffix.contains(word) {
+                suggestions_with_suffix.push(word.clone());
             }
+        }
+
+        // Composite ranking: kind (exact vs. suffix-expanded), then shared
+        // prefix length, edit distance and candidate length, each scaled by
+        // `self.ranking_weights`.
+        let exact_matches = self.cache.get(&splitted_string.1).cloned().unwrap_or_default();
+        let weights = self.ranking_weights;
+        suggestions_with_suffix.sort_by(|a, b| {
+            candidate_kind(a, &exact_matches)
+                .cmp(&candidate_kind(b, &exact_matches))
+                .then_with(|| {
+                    let prefix_a = shared_prefix_len(&phonetic, a) as i32 * weights.prefix;
+                    let prefix_b = shared_prefix_len(&phonetic, b) as i32 * weights.prefix;
+                    prefix_b.cmp(&prefix_a)
+                })
+                .then_with(|| {
+                    let dist_a = bengali_edit_distance(&phonetic, a) as i32 * weights.distance;
+                    let dist_b = bengali_edit_distance(&phonetic, b) as i32 * weights.distance;
+                    dist_a.cmp(&dist_b)
+                })
+                .then_with(|| {
+                    let len_a = a.chars().count() as i32 * weights.length;
+                    let len_b = b.chars().count() as i32 * weights.length;
+                    len_a.cmp(&len_b)
+                })
+        });
+
+        // Float previously-selected words for this key to the very front,
+        // most-selected first; the stable sort above still decides the
+        // relative order of everything else.
+        suggestions_with_suffix.sort_by_key(|word| {
+            learned
+                .iter()
+                .position(|selected| selected == word)
+                .unwrap_or(usize::MAX)
         });
 
         suggestions.append(&mut suggestions_with_suffix);
 
+        // Trie-completions rank below the dictionary/suffix matches above,
+        // but above the raw phonetic fallback below.
+        let mut completions = self.trie_completions(&splitted_string.1, &phonetic);
+        completions.retain(|word| !suggestions.contains(word));
+        suggestions.append(&mut completions);
+
         // Last Item: Phonetic. Check if it already contains.
         if !suggestions.contains(&phonetic) {
             suggestions.push(phonetic);
@@ -188,16 +344,25 @@ mod tests {
     use super::PhoneticSuggestion;
     use rustc_hash::FxHashMap;
 
+    /// A learned-words path under the temp dir, guaranteed not to already
+    /// exist, so a test starts with no learned selections regardless of
+    /// what's in the real user data directory.
+    fn fresh_learned_path(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("riti_test_suggestion_{}.json", name));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
     #[test]
     fn test_emoticon() {
-        let mut suggestion = PhoneticSuggestion::new();
+        let mut suggestion = PhoneticSuggestion::new_with_learned_path(fresh_learned_path("emoticon"));
 
         assert_eq!(suggestion.suggest(":)"), vec![":)", "ঃ)"]);
     }
 
     #[test]
     fn test_suggestion() {
-        let mut suggestion = PhoneticSuggestion::new();
+        let mut suggestion = PhoneticSuggestion::new_with_learned_path(fresh_learned_path("suggestion"));
 
         assert_eq!(
             suggestion.suggest("a"),
@@ -207,26 +372,34 @@ mod tests {
                 "া",
                 "এ",
                 "অ্যা",
-                "অ্যাঁ"
+                "অ্যাঁ",
+                // Trie completions: other dictionary words sharing the "আ"
+                // prefix, ranked below the exact matches above.
+                "আঁশ",
+                "আশ",
+                "আস"
             ]
         );
         assert_eq!(
+            // "আঁশ" now ranks ahead of "এস": it shares the leading "আ"
+            // syllable with the typed "আস", which the composite ranking
+            // weighs before raw edit distance.
             suggestion.suggest("as"),
-            vec!["আস", "আশ", "এস", "আঁশ"]
+            vec!["আস", "আশ", "আঁশ", "এস"]
         );
         assert_eq!(
             suggestion.suggest("asgulo"),
             vec![
                 "আসগুলো",
                 "আশগুলো",
-                "এসগুলো",
                 "আঁশগুলো",
+                "এসগুলো",
                 "আসগুল"
             ]
         );
         assert_eq!(
             suggestion.suggest("(as)"),
-            vec!["(আস)", "(আশ)", "(এস)", "(আঁশ)"]
+            vec!["(আস)", "(আশ)", "(আঁশ)", "(এস)"]
         );
     }
 
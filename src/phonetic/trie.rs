@@ -0,0 +1,91 @@
+// A minimal character trie over dictionary headwords, used for
+// prefix-autocompletion in `Database::autocomplete`.
+
+use std::collections::BTreeMap;
+
+// A `BTreeMap` (rather than a `HashMap`) keeps children in `char` order, so
+// `complete`'s DFS walk - and therefore `suggest`'s ranking - is
+// deterministic across runs.
+#[derive(Default)]
+struct TrieNode {
+    children: BTreeMap<char, TrieNode>,
+    is_terminal: bool,
+}
+
+#[derive(Default)]
+pub(crate) struct Trie {
+    root: TrieNode,
+}
+
+impl Trie {
+    pub(crate) fn new() -> Self {
+        Trie::default()
+    }
+
+    /// Insert `word` into the trie, one node per character.
+    pub(crate) fn insert(&mut self, word: &str) {
+        let mut node = &mut self.root;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_terminal = true;
+    }
+
+    /// Every inserted word that starts with `prefix`, found by walking down
+    /// to the node matching `prefix` and then collecting descendant
+    /// terminal words by DFS.
+    pub(crate) fn complete(&self, prefix: &str) -> Vec<String> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            match node.children.get(&c) {
+                Some(child) => node = child,
+                None => return Vec::new(),
+            }
+        }
+
+        let mut words = Vec::new();
+        collect(node, &mut prefix.to_owned(), &mut words);
+        words
+    }
+}
+
+fn collect(node: &TrieNode, prefix: &mut String, words: &mut Vec<String>) {
+    if node.is_terminal {
+        words.push(prefix.clone());
+    }
+
+    for (&c, child) in &node.children {
+        prefix.push(c);
+        collect(child, prefix, words);
+        prefix.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Trie;
+
+    #[test]
+    fn test_complete() {
+        let mut trie = Trie::new();
+        for word in ["আ", "আস", "আশ", "আঁশ", "এ"] {
+            trie.insert(word);
+        }
+
+        assert_eq!(
+            trie.complete("আ"),
+            vec!["আ", "আঁশ", "আশ", "আস"]
+        );
+        assert_eq!(trie.complete("এ"), vec!["এ"]);
+        assert_eq!(trie.complete("ও"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_insert_is_idempotent() {
+        let mut trie = Trie::new();
+        trie.insert("আস");
+        trie.insert("আস");
+
+        assert_eq!(trie.complete("আ"), vec!["আস"]);
+    }
+}
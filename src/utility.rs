@@ -0,0 +1,18 @@
+// Character classification helpers shared across the phonetic engine.
+
+pub(crate) trait Utility {
+    /// Is this an independent Bengali vowel (স্বরবর্ণ)?
+    fn is_vowel(&self) -> bool;
+    /// Is this a Bengali vowel-sign/kar (স্বরচিহ্ন)?
+    fn is_kar(&self) -> bool;
+}
+
+impl Utility for char {
+    fn is_vowel(&self) -> bool {
+        matches!(*self, '\u{0985}'..='\u{0994}')
+    }
+
+    fn is_kar(&self) -> bool {
+        matches!(*self, '\u{09BE}'..='\u{09CC}' | '\u{09D7}')
+    }
+}